@@ -1,39 +1,41 @@
+use std::sync::atomic::Ordering;
 use std::thread;
 use std::time::Duration;
-use rust_supervisor::{Supervisor, SupervisorConfig, RestartStrategy};
+use rust_supervisor::{Supervisor, SupervisorConfig};
 
 fn main() {
     println!("Starting supervision system...");
-    
+
     // Create a supervisor with default configuration
     let mut supervisor = Supervisor::new(SupervisorConfig::default());
-    
+
     // Add a process that will fail periodically
-    supervisor.add_process("unstable_process", || {
+    supervisor.add_process("unstable_process", |_stop_flag| {
         thread::spawn(|| {
             println!("Unstable process started");
-            
+
             // Simulate work that eventually fails
             let duration = Duration::from_secs(2);
             thread::sleep(duration);
-            
+
             println!("Unstable process failing!");
             panic!("Simulated error in unstable process");
         })
     });
-    
+
     // Add a stable process that depends on the first one
-    supervisor.add_process("stable_process", || {
-        thread::spawn(|| {
+    supervisor.add_process("stable_process", |stop_flag| {
+        thread::spawn(move || {
             println!("Stable process started");
-            
-            // Infinite loop with periodic logging
+
+            // Loop with periodic logging, exiting cleanly once asked to stop
             let mut counter = 0;
-            loop {
+            while !stop_flag.load(Ordering::SeqCst) {
                 thread::sleep(Duration::from_secs(1));
                 counter += 1;
                 println!("Stable process running (iteration {})", counter);
             }
+            println!("Stable process stopping cooperatively");
         })
     });
     
@@ -59,6 +61,9 @@ fn main() {
             }
         }
     }
-    
+
+    // Stop the whole tree, dependents before their dependencies
+    supervisor.shutdown_all();
+
     println!("Demo ended");
 }
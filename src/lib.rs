@@ -1,5 +1,5 @@
 //! # rust_supervisor
-//! 
+//!
 //! `rust_supervisor` is a library inspired by Erlang/OTP's supervision system,
 //! allowing automatic process restart when they fail.
 //!
@@ -9,8 +9,11 @@
 //! * Flexible restart policy configuration
 //! * Process dependency management
 //! * Automatic process state monitoring
+//! * Nested supervision trees
 
 use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::{Duration, Instant};
@@ -26,6 +29,50 @@ pub enum RestartStrategy {
     RestForOne,
 }
 
+/// Defines how long to wait before respawning a process that is eligible
+/// for restart
+///
+/// Without a delay, a process that fails immediately on boot (for example
+/// because a dependency isn't reachable yet) burns through its entire
+/// restart budget in a tight loop instead of giving the dependency time to
+/// come online.
+#[derive(Debug)]
+pub enum ActorRestartStrategy {
+    /// Restart as soon as the process is detected as failed
+    Immediate,
+    /// Wait `base * (n + 1)` before the `n`-th restart of a process, so the
+    /// first restart (`n == 0`) waits `base`
+    LinearBackOff {
+        /// Delay applied for the first restart, scaled linearly afterwards
+        base: Duration,
+    },
+    /// Wait `base * multiplier.pow(n)` before the `n`-th restart of a
+    /// process, so the first restart (`n == 0`) also waits `base`
+    ExponentialBackOff {
+        /// Delay applied for the first restart, scaled exponentially afterwards
+        base: Duration,
+        /// Factor the delay grows by at each subsequent restart
+        multiplier: u32,
+    },
+}
+
+impl ActorRestartStrategy {
+    /// Computes the delay to wait before performing the `n`-th restart of a
+    /// process (`n` is 0-based, so `n == 0` is the first restart)
+    fn delay_for(&self, n: u32) -> Duration {
+        match self {
+            ActorRestartStrategy::Immediate => Duration::from_secs(0),
+            ActorRestartStrategy::LinearBackOff { base } => base
+                .checked_mul(n.saturating_add(1))
+                .unwrap_or(Duration::MAX),
+            ActorRestartStrategy::ExponentialBackOff { base, multiplier } => {
+                let factor = multiplier.checked_pow(n).unwrap_or(u32::MAX);
+                base.checked_mul(factor).unwrap_or(Duration::MAX)
+            }
+        }
+    }
+}
+
 /// Represents the current state of a process
 #[derive(Debug)]
 pub enum ProcessState {
@@ -33,10 +80,66 @@ pub enum ProcessState {
     Running,
     /// Process has failed
     Failed,
-    /// Process is being restarted
+    /// Process is waiting for its back-off delay to elapse before being restarted
     Restarting,
+    /// Process has been signalled to stop and is finishing its shutdown
+    Stopping,
     /// Process is stopped (will not be restarted)
     Stopped,
+    /// Process exited normally (no panic); not restarted unless its policy is `Always`
+    Completed,
+}
+
+/// Defines when a process should be restarted after it exits
+///
+/// Mirrors OTP's transient/permanent/temporary child specs: not every exit
+/// is a crash, and not every crash deserves a restart.
+#[derive(Debug)]
+pub enum RestartPolicy {
+    /// Restart the process no matter how it exited
+    Always,
+    /// Restart the process only if it exited by panicking
+    OnFailureOnly,
+    /// Never restart the process; any exit transitions it to `Stopped`
+    Temporary,
+}
+
+/// Errors returned by the dynamic child-management API
+#[derive(Debug)]
+pub enum SupervisorError {
+    /// No entry with this name is managed by the supervisor
+    NotFound,
+    /// The entry is already running and cannot be started or deleted as-is
+    AlreadyRunning,
+    /// The entry is mid-restart and cannot be started or deleted right now
+    Restarting,
+}
+
+impl fmt::Display for SupervisorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SupervisorError::NotFound => write!(f, "no such child"),
+            SupervisorError::AlreadyRunning => write!(f, "child is already running"),
+            SupervisorError::Restarting => write!(f, "child is mid-restart"),
+        }
+    }
+}
+
+impl std::error::Error for SupervisorError {}
+
+/// A point-in-time snapshot of a single managed entry, as returned by
+/// `which_children`
+#[derive(Debug)]
+pub struct ChildSummary {
+    /// Name the entry was added under
+    pub name: String,
+    /// Current state of the entry
+    pub state: ProcessState,
+    /// Number of restarts performed since it was last healthy for `max_time`
+    pub restart_count: u32,
+    /// Whether the entry currently has a live handle (a running thread, or
+    /// a child supervisor that hasn't been stopped)
+    pub has_handle: bool,
 }
 
 /// Supervisor configuration
@@ -48,6 +151,11 @@ pub struct SupervisorConfig {
     pub max_time: Duration,
     /// Restart strategy to use
     pub restart_strategy: RestartStrategy,
+    /// Back-off strategy applied before respawning a failed process
+    pub actor_restart_strategy: ActorRestartStrategy,
+    /// How long `stop_process` waits for a process to honor its stop signal
+    /// before giving up and detaching it
+    pub shutdown_timeout: Duration,
 }
 
 impl Default for SupervisorConfig {
@@ -57,6 +165,8 @@ impl Default for SupervisorConfig {
             max_restarts: 3,
             max_time: Duration::from_secs(5),
             restart_strategy: RestartStrategy::OneForOne,
+            actor_restart_strategy: ActorRestartStrategy::Immediate,
+            shutdown_timeout: Duration::from_secs(5),
         }
     }
 }
@@ -69,18 +179,86 @@ struct ProcessInfo {
     restart_times: Vec<Instant>,
     /// Current process state
     state: ProcessState,
-    /// Factory for creating a new instance of the process
-    factory: Box<dyn Fn() -> thread::JoinHandle<()> + Send + 'static>,
+    /// Factory for creating a new instance of the process; receives the stop
+    /// token the spawned thread should poll to know when to exit cleanly
+    factory: Box<dyn Fn(Arc<AtomicBool>) -> thread::JoinHandle<()> + Send + 'static>,
+    /// Number of restarts performed since the process was last healthy for `max_time`
+    restart_count: u32,
+    /// Instant the process last entered `Running`, used to decide when
+    /// `restart_count` should be reset back to zero
+    running_since: Option<Instant>,
+    /// While `Restarting`, the instant at which the delayed respawn is due
+    restart_at: Option<Instant>,
+    /// Policy deciding whether this process is restarted on exit
+    restart_policy: RestartPolicy,
+    /// Token the current run of the process polls to know when to stop;
+    /// a fresh one is handed out on every (re)start
+    stop_flag: Arc<AtomicBool>,
+    /// Lifecycle hooks fired as the process starts, restarts, stops, or
+    /// gives up
+    callbacks: Callbacks,
+    /// Set when this process's own restart budget was exhausted, as opposed
+    /// to `Stopped` from a clean `Temporary` exit or a manual `stop_process`/
+    /// `restart_child` call; only this should be reported up as a give-up
+    gave_up: bool,
+}
+
+/// A lifecycle hook: receives the process name and its current restart count
+pub type Callback = Box<dyn Fn(&str, u32) + Send + Sync>;
+
+/// Lifecycle hooks invoked as a process transitions through the supervisor
+///
+/// Each hook receives the process name and its current restart count, so
+/// it can emit metrics, log structured events, page an operator when a
+/// process is finally abandoned, or warm up external resources before a
+/// restart.
+#[derive(Default)]
+pub struct Callbacks {
+    /// Invoked just before a process is (re)spawned by an automatic restart,
+    /// `start_child`, or a subtree rebuild. Does not fire for the very first
+    /// spawn performed by `add_process`/`add_process_with_policy`, since
+    /// callbacks can only be attached afterwards via `set_callbacks`
+    pub before_start: Option<Callback>,
+    /// Invoked right after a process has been respawned following a failure
+    pub after_restart: Option<Callback>,
+    /// Invoked right after a process has been stopped
+    pub after_stop: Option<Callback>,
+    /// Invoked once a process's restart budget is exhausted and it is
+    /// permanently `Stopped`
+    pub after_max_restarts: Option<Callback>,
+}
+
+/// A single entry supervised by a `Supervisor`: either a worker process or
+/// an entire nested supervision subtree
+enum Supervisee {
+    /// A plain worker process
+    Process(ProcessInfo),
+    /// A child supervisor, supervising its own processes and subtrees
+    Supervisor(Supervisor),
 }
 
 /// Supervisor that manages a set of processes
 pub struct Supervisor {
-    /// Map of managed processes, with their name as the key
-    processes: Arc<Mutex<HashMap<String, ProcessInfo>>>,
+    /// Map of managed entries (processes or nested supervisors), by name
+    processes: Arc<Mutex<HashMap<String, Supervisee>>>,
     /// Supervisor configuration
     config: SupervisorConfig,
-    /// Map of dependencies between processes
+    /// Map of dependencies between entries
     dependencies: HashMap<String, Vec<String>>,
+    /// State this supervisor is seen in by its parent, when it is itself
+    /// supervised as a child (see `add_supervisor`)
+    parent_view_state: Arc<Mutex<ProcessState>>,
+    /// Restart history used by the parent to apply its own restart budget
+    /// to this supervisor, when it is itself supervised as a child
+    parent_restart_times: Arc<Mutex<Vec<Instant>>>,
+    /// Set once this supervisor has exhausted its own restart budget and
+    /// given up on one of its entries, signalling its parent to act
+    exhausted: Arc<AtomicBool>,
+    /// Set when the *parent's* restart budget for this child was exhausted,
+    /// as opposed to `parent_view_state` being `Stopped` from a manual
+    /// `stop_process` call; only this should make the parent treat the
+    /// subtree as having given up
+    parent_gave_up: Arc<AtomicBool>,
 }
 
 impl Supervisor {
@@ -100,6 +278,10 @@ impl Supervisor {
             processes: Arc::new(Mutex::new(HashMap::new())),
             config,
             dependencies: HashMap::new(),
+            parent_view_state: Arc::new(Mutex::new(ProcessState::Running)),
+            parent_restart_times: Arc::new(Mutex::new(Vec::new())),
+            exhausted: Arc::new(AtomicBool::new(false)),
+            parent_gave_up: Arc::new(AtomicBool::new(false)),
         }
     }
 
@@ -108,42 +290,108 @@ impl Supervisor {
     /// # Arguments
     ///
     /// * `name` - Unique process name
-    /// * `factory` - Function that creates and starts the process
+    /// * `factory` - Function that creates and starts the process; it receives a
+    ///   stop token that long-running loops should poll to exit cleanly
     ///
     /// # Example
     ///
     /// ```
-    /// supervisor.add_process("worker", || {
-    ///     thread::spawn(|| {
-    ///         // Worker code...
+    /// supervisor.add_process("worker", |stop_flag| {
+    ///     thread::spawn(move || {
+    ///         while !stop_flag.load(Ordering::SeqCst) {
+    ///             // Worker code...
+    ///         }
     ///     })
     /// });
     /// ```
     pub fn add_process<F>(&mut self, name: &str, factory: F)
     where
-        F: Fn() -> thread::JoinHandle<()> + Send + 'static,
+        F: Fn(Arc<AtomicBool>) -> thread::JoinHandle<()> + Send + 'static,
+    {
+        self.add_process_with_policy(name, factory, RestartPolicy::Always);
+    }
+
+    /// Adds a process to monitor with an explicit restart policy
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Unique process name
+    /// * `factory` - Function that creates and starts the process; it receives a
+    ///   stop token that long-running loops should poll to exit cleanly
+    /// * `policy` - Whether to restart the process on failure, on any exit, or never
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// // A one-off job that should not be restarted once it completes
+    /// supervisor.add_process_with_policy("migration", |_stop_flag| {
+    ///     thread::spawn(|| {
+    ///         // Run the migration once...
+    ///     })
+    /// }, RestartPolicy::Temporary);
+    /// ```
+    pub fn add_process_with_policy<F>(&mut self, name: &str, factory: F, policy: RestartPolicy)
+    where
+        F: Fn(Arc<AtomicBool>) -> thread::JoinHandle<()> + Send + 'static,
     {
         let factory_box = Box::new(factory);
-        let handle = (factory_box)();
-        
+        let stop_flag = Arc::new(AtomicBool::new(false));
+        let handle = (factory_box)(Arc::clone(&stop_flag));
+
         let mut processes = self.processes.lock().unwrap();
         processes.insert(
             name.to_string(),
-            ProcessInfo {
+            Supervisee::Process(ProcessInfo {
                 handle: Some(handle),
                 restart_times: Vec::new(),
                 state: ProcessState::Running,
                 factory: factory_box,
-            },
+                restart_count: 0,
+                running_since: Some(Instant::now()),
+                restart_at: None,
+                restart_policy: policy,
+                stop_flag,
+                callbacks: Callbacks::default(),
+                gave_up: false,
+            }),
         );
     }
 
-    /// Declares a dependency between two processes
+    /// Supervises a child supervisor as a nested subtree
+    ///
+    /// If the child exhausts its own restart budget and gives up on one of
+    /// its entries, it reports that failure up to this supervisor, which
+    /// then applies its own `RestartStrategy` to the child exactly as it
+    /// would to a failed process, tearing down and rebuilding the child's
+    /// entire subtree.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Unique name for the child supervisor
+    /// * `child` - The child supervisor, with its own processes already added
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut child = Supervisor::new(SupervisorConfig::default());
+    /// child.add_process("child_worker", |stop_flag| {
+    ///     thread::spawn(move || { /* ... */ })
+    /// });
+    /// supervisor.add_supervisor("child_tree", child);
+    /// ```
+    pub fn add_supervisor(&mut self, name: &str, child: Supervisor) {
+        child.start_monitoring();
+
+        let mut processes = self.processes.lock().unwrap();
+        processes.insert(name.to_string(), Supervisee::Supervisor(child));
+    }
+
+    /// Declares a dependency between two entries
     ///
     /// # Arguments
     ///
-    /// * `process` - Name of the process that depends on another
-    /// * `depends_on` - Name of the process that the first one depends on
+    /// * `process` - Name of the entry that depends on another
+    /// * `depends_on` - Name of the entry that the first one depends on
     ///
     /// # Example
     ///
@@ -154,10 +402,42 @@ impl Supervisor {
     pub fn add_dependency(&mut self, process: &str, depends_on: &str) {
         self.dependencies
             .entry(process.to_string())
-            .or_insert_with(Vec::new)
+            .or_default()
             .push(depends_on.to_string());
     }
 
+    /// Attaches lifecycle callbacks to an already-added process
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the process to attach callbacks to
+    /// * `callbacks` - Hooks to invoke on start, restart, stop, and giving up
+    ///
+    /// # Returns
+    ///
+    /// `true` if the process was found, `false` otherwise
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// supervisor.set_callbacks("worker1", Callbacks {
+    ///     after_max_restarts: Some(Box::new(|name, count| {
+    ///         eprintln!("{name} gave up after {count} restarts");
+    ///     })),
+    ///     ..Default::default()
+    /// });
+    /// ```
+    pub fn set_callbacks(&mut self, name: &str, callbacks: Callbacks) -> bool {
+        let mut processes = self.processes.lock().unwrap();
+        match processes.get_mut(name) {
+            Some(Supervisee::Process(info)) => {
+                info.callbacks = callbacks;
+                true
+            }
+            _ => false,
+        }
+    }
+
     /// Starts monitoring processes
     ///
     /// This method launches a monitoring thread that periodically checks
@@ -172,39 +452,127 @@ impl Supervisor {
         let processes = Arc::clone(&self.processes);
         let config = self.config.clone();
         let dependencies = self.dependencies.clone();
-        
+        let exhausted = Arc::clone(&self.exhausted);
+
         thread::spawn(move || {
             loop {
                 thread::sleep(Duration::from_millis(100));
-                
-                // First, collect information about failed processes without modifying the map
+
+                // First, collect information about finished processes and gave-up
+                // child supervisors, without restarting anything yet
                 let mut failed_processes = Vec::new();
+                let mut children_giving_up = Vec::new();
                 {
                     let mut processes_lock = processes.lock().unwrap();
-                    for (name, info) in processes_lock.iter_mut() {
-                        if let Some(handle) = &info.handle {
-                            if handle.is_finished() {
-                                info.state = ProcessState::Failed;
-                                info.handle = None;
-                                
-                                // Check if we can restart
+                    for (name, supervisee) in processes_lock.iter_mut() {
+                        match supervisee {
+                            Supervisee::Process(info) => {
+                                let finished =
+                                    matches!(&info.handle, Some(handle) if handle.is_finished());
+                                if !finished {
+                                    continue;
+                                }
+
+                                // Joining a finished handle can't block; it tells us
+                                // whether the thread panicked (Err) or returned normally (Ok)
+                                let panicked = info.handle.take().unwrap().join().is_err();
+                                info.state = if panicked {
+                                    ProcessState::Failed
+                                } else {
+                                    ProcessState::Completed
+                                };
+
+                                let eligible_for_restart = match info.restart_policy {
+                                    RestartPolicy::Always => true,
+                                    RestartPolicy::OnFailureOnly => panicked,
+                                    RestartPolicy::Temporary => false,
+                                };
+
+                                if !eligible_for_restart {
+                                    if matches!(info.restart_policy, RestartPolicy::Temporary) {
+                                        info.state = ProcessState::Stopped;
+                                    }
+                                    continue;
+                                }
+
                                 let now = Instant::now();
-                                info.restart_times.retain(|time| now.duration_since(*time) < config.max_time);
-                                
+                                info.restart_times
+                                    .retain(|time| now.duration_since(*time) < config.max_time);
+
                                 if info.restart_times.len() < config.max_restarts {
                                     failed_processes.push(name.clone());
                                 } else {
                                     // Too many restarts, stop the process
                                     info.state = ProcessState::Stopped;
+                                    info.gave_up = true;
+                                    if let Some(callback) = &info.callbacks.after_max_restarts {
+                                        callback(name, info.restart_count);
+                                    }
+                                }
+                            }
+                            Supervisee::Supervisor(child) => {
+                                if !child.exhausted.load(Ordering::SeqCst) {
+                                    continue;
+                                }
+                                if matches!(
+                                    *child.parent_view_state.lock().unwrap(),
+                                    ProcessState::Stopped
+                                ) {
+                                    continue;
+                                }
+
+                                *child.parent_view_state.lock().unwrap() = ProcessState::Failed;
+
+                                let now = Instant::now();
+                                let mut restart_times = child.parent_restart_times.lock().unwrap();
+                                restart_times.retain(|time| now.duration_since(*time) < config.max_time);
+
+                                if restart_times.len() < config.max_restarts {
+                                    drop(restart_times);
+                                    failed_processes.push(name.clone());
+                                } else {
+                                    // Too many restarts; the subtree still needs to be
+                                    // torn down before it's marked `Stopped` below, once
+                                    // the lock covering every other entry is released
+                                    children_giving_up.push(name.clone());
                                 }
                             }
                         }
                     }
                 }
-                
-                // Now handle the restart logic for each failed process
+
+                // Tear down every subtree whose parent-tracked restart budget
+                // was exhausted, so its worker threads don't keep running
+                // orphaned once it's marked `Stopped` (the child's own monitor
+                // thread keeps looping). Each child is removed from the map first so the
+                // blocking `shutdown_all` doesn't hold the lock other entries
+                // (and API calls like `which_children`/`stop_process`) need.
+                for name in &children_giving_up {
+                    let child = {
+                        let mut processes_lock = processes.lock().unwrap();
+                        match processes_lock.remove(name) {
+                            Some(Supervisee::Supervisor(child)) => child,
+                            Some(other) => {
+                                processes_lock.insert(name.clone(), other);
+                                continue;
+                            }
+                            None => continue,
+                        }
+                    };
+
+                    child.shutdown_all();
+                    *child.parent_view_state.lock().unwrap() = ProcessState::Stopped;
+                    child.parent_gave_up.store(true, Ordering::SeqCst);
+
+                    processes
+                        .lock()
+                        .unwrap()
+                        .insert(name.clone(), Supervisee::Supervisor(child));
+                }
+
+                // Now schedule the restart logic for each failed entry
                 for failed_process in failed_processes {
-                    // Determine which processes to restart based on the strategy
+                    // Determine which entries to restart based on the strategy
                     let processes_to_restart = {
                         let processes_lock = processes.lock().unwrap();
                         match config.restart_strategy {
@@ -212,7 +580,7 @@ impl Supervisor {
                             RestartStrategy::OneForAll => processes_lock.keys().cloned().collect(),
                             RestartStrategy::RestForOne => {
                                 let mut to_restart = vec![failed_process.clone()];
-                                // Add processes that depend on this one
+                                // Add entries that depend on this one
                                 for (proc_name, deps) in &dependencies {
                                     if deps.contains(&failed_process) {
                                         to_restart.push(proc_name.clone());
@@ -222,32 +590,206 @@ impl Supervisor {
                             }
                         }
                     };
-                    
-                    // Restart all necessary processes
+
+                    // Put every affected entry in `Restarting` and schedule its
+                    // respawn after its own back-off delay has elapsed
                     let now = Instant::now();
+                    let mut processes_lock = processes.lock().unwrap();
                     for proc_name in processes_to_restart {
-                        let mut processes_lock = processes.lock().unwrap();
-                        if let Some(proc_info) = processes_lock.get_mut(&proc_name) {
-                            proc_info.state = ProcessState::Restarting;
-                            proc_info.handle = Some((proc_info.factory)());
-                            proc_info.restart_times.push(now);
-                            proc_info.state = ProcessState::Running;
+                        if let Some(supervisee) = processes_lock.get_mut(&proc_name) {
+                            match supervisee {
+                                Supervisee::Process(proc_info) => {
+                                    let delay = config
+                                        .actor_restart_strategy
+                                        .delay_for(proc_info.restart_count);
+                                    proc_info.state = ProcessState::Restarting;
+                                    proc_info.restart_at = Some(now + delay);
+                                }
+                                Supervisee::Supervisor(child) => {
+                                    *child.parent_view_state.lock().unwrap() =
+                                        ProcessState::Restarting;
+                                }
+                            }
                         }
                     }
                 }
+
+                // Collect the processes whose back-off delay has elapsed, the
+                // still-running handles they need to replace (a `OneForAll`/
+                // `RestForOne` sweep can put a healthy sibling in `Restarting`
+                // without its run ever failing), and the child supervisors
+                // whose subtree needs rebuilding -- without performing any of
+                // that blocking work while holding the lock
+                let now = Instant::now();
+                let mut due_for_respawn = Vec::new();
+                let mut stale_handles = Vec::new();
+                let mut children_restarting = Vec::new();
+                {
+                    let mut processes_lock = processes.lock().unwrap();
+                    for (name, supervisee) in processes_lock.iter_mut() {
+                        match supervisee {
+                            Supervisee::Process(info) => match info.state {
+                                ProcessState::Restarting
+                                    if info.restart_at.map(|at| now >= at).unwrap_or(true) =>
+                                {
+                                    if let Some(handle) = info.handle.take() {
+                                        stale_handles.push((Arc::clone(&info.stop_flag), handle));
+                                    }
+                                    due_for_respawn.push(name.clone());
+                                }
+                                ProcessState::Running => {
+                                    // A process that has stayed healthy long enough starts fresh
+                                    if let Some(running_since) = info.running_since {
+                                        if now.duration_since(running_since) > config.max_time {
+                                            info.restart_count = 0;
+                                        }
+                                    }
+                                }
+                                _ => {}
+                            },
+                            Supervisee::Supervisor(child) => {
+                                let restarting = matches!(
+                                    *child.parent_view_state.lock().unwrap(),
+                                    ProcessState::Restarting
+                                );
+                                if restarting {
+                                    children_restarting.push(name.clone());
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Stop and join every still-running handle being replaced
+                // before its respawn below, so a `OneForAll`/`RestForOne`
+                // restart of a healthy sibling can never leave its previous
+                // thread detached and running alongside the new one
+                for (stop_flag, handle) in stale_handles {
+                    stop_flag.store(true, Ordering::SeqCst);
+                    let deadline = Instant::now() + config.shutdown_timeout;
+                    while !handle.is_finished() && Instant::now() < deadline {
+                        thread::sleep(Duration::from_millis(10));
+                    }
+                    if handle.is_finished() {
+                        let _ = handle.join();
+                    }
+                    // Otherwise it did not honor the stop signal in time;
+                    // dropping it here detaches the still-running thread, same
+                    // as `stop_process`.
+                }
+
+                {
+                    let mut processes_lock = processes.lock().unwrap();
+                    for name in &due_for_respawn {
+                        if let Some(Supervisee::Process(info)) = processes_lock.get_mut(name) {
+                            if let Some(callback) = &info.callbacks.before_start {
+                                callback(name, info.restart_count);
+                            }
+                            let stop_flag = Arc::new(AtomicBool::new(false));
+                            info.handle = Some((info.factory)(Arc::clone(&stop_flag)));
+                            info.stop_flag = stop_flag;
+                            info.restart_times.push(now);
+                            info.restart_count += 1;
+                            info.restart_at = None;
+                            info.state = ProcessState::Running;
+                            info.running_since = Some(now);
+                            if let Some(callback) = &info.callbacks.after_restart {
+                                callback(name, info.restart_count);
+                            }
+                        }
+                    }
+                }
+
+                // Rebuild each flagged child's subtree without holding the
+                // parent's `processes` lock, so other entries (and API calls
+                // like `which_children`/`stop_process`) are not frozen for
+                // `shutdown_timeout` per child being torn down
+                for name in &children_restarting {
+                    let child = {
+                        let mut processes_lock = processes.lock().unwrap();
+                        match processes_lock.remove(name) {
+                            Some(Supervisee::Supervisor(child)) => child,
+                            Some(other) => {
+                                processes_lock.insert(name.clone(), other);
+                                continue;
+                            }
+                            None => continue,
+                        }
+                    };
+
+                    child.rebuild();
+                    child.parent_restart_times.lock().unwrap().push(now);
+                    *child.parent_view_state.lock().unwrap() = ProcessState::Running;
+
+                    processes
+                        .lock()
+                        .unwrap()
+                        .insert(name.clone(), Supervisee::Supervisor(child));
+                }
+
+                // Propagate exhaustion up if this supervisor has itself given up
+                // on one of its own entries. A `Stopped` entry alone isn't
+                // enough: a `Temporary` process that exited cleanly, or an
+                // entry a manual `stop_process`/`restart_child` call put there,
+                // is also `Stopped` but is not a give-up.
+                let gave_up = processes.lock().unwrap().values().any(|supervisee| match supervisee {
+                    Supervisee::Process(info) => info.gave_up,
+                    Supervisee::Supervisor(child) => child.parent_gave_up.load(Ordering::SeqCst),
+                });
+                if gave_up {
+                    exhausted.store(true, Ordering::SeqCst);
+                }
             }
         });
     }
 
+    /// Tears down this supervisor's entire subtree and relaunches every
+    /// entry fresh, as if it had just been added
+    fn rebuild(&self) {
+        self.shutdown_all();
+
+        let mut processes = self.processes.lock().unwrap();
+        for (name, supervisee) in processes.iter_mut() {
+            match supervisee {
+                Supervisee::Process(info) => {
+                    info.restart_times.clear();
+                    info.restart_count = 0;
+                    info.restart_at = None;
+                    info.gave_up = false;
+                    if let Some(callback) = &info.callbacks.before_start {
+                        callback(name, info.restart_count);
+                    }
+                    let stop_flag = Arc::new(AtomicBool::new(false));
+                    info.handle = Some((info.factory)(Arc::clone(&stop_flag)));
+                    info.stop_flag = stop_flag;
+                    info.state = ProcessState::Running;
+                    info.running_since = Some(Instant::now());
+                }
+                Supervisee::Supervisor(child) => {
+                    child.exhausted.store(false, Ordering::SeqCst);
+                    child.parent_gave_up.store(false, Ordering::SeqCst);
+                    child.parent_restart_times.lock().unwrap().clear();
+                    *child.parent_view_state.lock().unwrap() = ProcessState::Running;
+                    child.rebuild();
+                }
+            }
+        }
+        self.exhausted.store(false, Ordering::SeqCst);
+    }
+
     /// Manually stops a process
     ///
+    /// Signals the process's stop token, then waits up to the configured
+    /// `shutdown_timeout` for it to exit on its own before detaching it. If
+    /// `name` refers to a nested supervisor, its entire subtree is stopped.
+    ///
     /// # Arguments
     ///
-    /// * `name` - Name of the process to stop
+    /// * `name` - Name of the entry to stop
     ///
     /// # Returns
     ///
-    /// `true` if the process was found and stopped, `false` otherwise
+    /// `true` if the entry was found and stopped, `false` otherwise
     ///
     /// # Example
     ///
@@ -255,27 +797,144 @@ impl Supervisor {
     /// let stopped = supervisor.stop_process("worker1");
     /// ```
     pub fn stop_process(&self, name: &str) -> bool {
-        let mut processes = self.processes.lock().unwrap();
-        if let Some(info) = processes.get_mut(name) {
-            if let Some(handle) = info.handle.take() {
-                // In a real implementation, you would want to send a cleaner stop signal
-                drop(handle);
-                info.state = ProcessState::Stopped;
-                return true;
+        enum Stoppable {
+            Process(Arc<AtomicBool>, thread::JoinHandle<()>),
+            Supervisor,
+        }
+
+        let stoppable = {
+            let mut processes = self.processes.lock().unwrap();
+            match processes.get_mut(name) {
+                Some(Supervisee::Process(info)) => {
+                    let handle = match info.handle.take() {
+                        Some(handle) => handle,
+                        None => {
+                            // A process waiting out its back-off has no thread to
+                            // join; cancel the pending respawn instead
+                            if !matches!(info.state, ProcessState::Restarting) {
+                                return false;
+                            }
+                            info.state = ProcessState::Stopped;
+                            info.restart_at = None;
+                            if let Some(callback) = &info.callbacks.after_stop {
+                                callback(name, info.restart_count);
+                            }
+                            return true;
+                        }
+                    };
+                    info.state = ProcessState::Stopping;
+                    Stoppable::Process(Arc::clone(&info.stop_flag), handle)
+                }
+                Some(Supervisee::Supervisor(_)) => Stoppable::Supervisor,
+                None => return false,
+            }
+        };
+
+        match stoppable {
+            Stoppable::Process(stop_flag, handle) => {
+                stop_flag.store(true, Ordering::SeqCst);
+
+                let deadline = Instant::now() + self.config.shutdown_timeout;
+                while !handle.is_finished() && Instant::now() < deadline {
+                    thread::sleep(Duration::from_millis(10));
+                }
+                if handle.is_finished() {
+                    let _ = handle.join();
+                }
+                // Otherwise the process did not honor the stop signal in time;
+                // dropping the handle here detaches the still-running thread.
+
+                let mut processes = self.processes.lock().unwrap();
+                if let Some(Supervisee::Process(info)) = processes.get_mut(name) {
+                    info.state = ProcessState::Stopped;
+                    if let Some(callback) = &info.callbacks.after_stop {
+                        callback(name, info.restart_count);
+                    }
+                }
+            }
+            Stoppable::Supervisor => {
+                let processes = self.processes.lock().unwrap();
+                if let Some(Supervisee::Supervisor(child)) = processes.get(name) {
+                    child.shutdown_all();
+                    *child.parent_view_state.lock().unwrap() = ProcessState::Stopped;
+                }
             }
         }
-        false
+
+        true
     }
 
-    /// Gets the current state of a process
+    /// Stops every managed entry, tearing down dependents before the
+    /// entries they depend on so a dependent is never left running against
+    /// an already-stopped dependency
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// supervisor.shutdown_all();
+    /// ```
+    pub fn shutdown_all(&self) {
+        for name in self.shutdown_order() {
+            self.stop_process(&name);
+        }
+    }
+
+    /// Orders every managed entry so that an entry always precedes anything
+    /// listed in its own `depends_on` set (topological sort of the
+    /// dependency graph); entries caught in a dependency cycle come last,
+    /// in name order
+    fn shutdown_order(&self) -> Vec<String> {
+        let names: Vec<String> = self.processes.lock().unwrap().keys().cloned().collect();
+
+        let mut in_degree: HashMap<String, usize> =
+            names.iter().cloned().map(|name| (name, 0)).collect();
+        for deps in self.dependencies.values() {
+            for dep in deps {
+                *in_degree.entry(dep.clone()).or_insert(0) += 1;
+            }
+        }
+
+        let mut ready: Vec<String> = in_degree
+            .iter()
+            .filter(|(_, degree)| **degree == 0)
+            .map(|(name, _)| name.clone())
+            .collect();
+        ready.sort();
+
+        let mut order = Vec::new();
+        while let Some(name) = ready.pop() {
+            if let Some(deps) = self.dependencies.get(&name) {
+                for dep in deps {
+                    if let Some(degree) = in_degree.get_mut(dep) {
+                        *degree -= 1;
+                        if *degree == 0 {
+                            ready.push(dep.clone());
+                            ready.sort();
+                        }
+                    }
+                }
+            }
+            order.push(name);
+        }
+
+        // Entries in a cycle never reach in-degree zero; stop them too
+        let mut remaining: Vec<String> =
+            names.into_iter().filter(|name| !order.contains(name)).collect();
+        remaining.sort();
+        order.extend(remaining);
+
+        order
+    }
+
+    /// Gets the current state of a process or child supervisor
     ///
     /// # Arguments
     ///
-    /// * `name` - Process name
+    /// * `name` - Entry name
     ///
     /// # Returns
     ///
-    /// The process state, or `None` if the process doesn't exist
+    /// The entry's state, or `None` if it doesn't exist
     ///
     /// # Example
     ///
@@ -286,7 +945,156 @@ impl Supervisor {
     /// ```
     pub fn get_process_state(&self, name: &str) -> Option<ProcessState> {
         let processes = self.processes.lock().unwrap();
-        processes.get(name).map(|info| info.state.clone())
+        processes.get(name).map(|supervisee| match supervisee {
+            Supervisee::Process(info) => info.state.clone(),
+            Supervisee::Supervisor(child) => child.parent_view_state.lock().unwrap().clone(),
+        })
+    }
+
+    /// Forces an immediate manual restart of an entry, regardless of its
+    /// restart policy or any back-off currently in progress
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the entry to restart
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// supervisor.restart_child("worker1")?;
+    /// ```
+    pub fn restart_child(&self, name: &str) -> Result<(), SupervisorError> {
+        if !self.processes.lock().unwrap().contains_key(name) {
+            return Err(SupervisorError::NotFound);
+        }
+        self.stop_process(name);
+        self.start_child(name)
+    }
+
+    /// Removes a stopped entry from the supervisor entirely
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the entry to delete
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// supervisor.delete_child("worker1")?;
+    /// ```
+    pub fn delete_child(&self, name: &str) -> Result<(), SupervisorError> {
+        let mut processes = self.processes.lock().unwrap();
+        let state = match processes.get(name) {
+            None => return Err(SupervisorError::NotFound),
+            Some(Supervisee::Process(info)) => info.state.clone(),
+            Some(Supervisee::Supervisor(child)) => child.parent_view_state.lock().unwrap().clone(),
+        };
+
+        match state {
+            ProcessState::Restarting => Err(SupervisorError::Restarting),
+            ProcessState::Running | ProcessState::Stopping => Err(SupervisorError::AlreadyRunning),
+            _ => {
+                processes.remove(name);
+                Ok(())
+            }
+        }
+    }
+
+    /// (Re)launches a previously added entry that is currently stopped
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the entry to start
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// supervisor.start_child("worker1")?;
+    /// ```
+    pub fn start_child(&self, name: &str) -> Result<(), SupervisorError> {
+        let mut processes = self.processes.lock().unwrap();
+        match processes.get_mut(name) {
+            None => Err(SupervisorError::NotFound),
+            Some(Supervisee::Process(info)) => match info.state {
+                ProcessState::Restarting => Err(SupervisorError::Restarting),
+                ProcessState::Running | ProcessState::Stopping => Err(SupervisorError::AlreadyRunning),
+                _ => {
+                    info.gave_up = false;
+                    if let Some(callback) = &info.callbacks.before_start {
+                        callback(name, info.restart_count);
+                    }
+                    let stop_flag = Arc::new(AtomicBool::new(false));
+                    info.handle = Some((info.factory)(Arc::clone(&stop_flag)));
+                    info.stop_flag = stop_flag;
+                    info.restart_times.clear();
+                    info.restart_count = 0;
+                    info.restart_at = None;
+                    info.state = ProcessState::Running;
+                    info.running_since = Some(Instant::now());
+                    Ok(())
+                }
+            },
+            Some(Supervisee::Supervisor(child)) => {
+                let state = child.parent_view_state.lock().unwrap().clone();
+                match state {
+                    ProcessState::Restarting => Err(SupervisorError::Restarting),
+                    ProcessState::Running | ProcessState::Stopping => {
+                        Err(SupervisorError::AlreadyRunning)
+                    }
+                    _ => {
+                        child.rebuild();
+                        *child.parent_view_state.lock().unwrap() = ProcessState::Running;
+                        Ok(())
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns a snapshot of every managed entry
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// for child in supervisor.which_children() {
+    ///     println!("{}: {:?}", child.name, child.state);
+    /// }
+    /// ```
+    pub fn which_children(&self) -> Vec<ChildSummary> {
+        let processes = self.processes.lock().unwrap();
+        processes
+            .iter()
+            .map(|(name, supervisee)| match supervisee {
+                Supervisee::Process(info) => ChildSummary {
+                    name: name.clone(),
+                    state: info.state.clone(),
+                    restart_count: info.restart_count,
+                    has_handle: info.handle.is_some(),
+                },
+                Supervisee::Supervisor(child) => {
+                    let state = child.parent_view_state.lock().unwrap().clone();
+                    let restart_count = child.parent_restart_times.lock().unwrap().len() as u32;
+                    let has_handle = !matches!(state, ProcessState::Stopped);
+                    ChildSummary {
+                        name: name.clone(),
+                        state,
+                        restart_count,
+                        has_handle,
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the number of entries currently managed, regardless of state
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let total = supervisor.count_children();
+    /// ```
+    pub fn count_children(&self) -> usize {
+        self.processes.lock().unwrap().len()
     }
 }
 
@@ -296,11 +1104,9 @@ impl Clone for SupervisorConfig {
         SupervisorConfig {
             max_restarts: self.max_restarts,
             max_time: self.max_time,
-            restart_strategy: match self.restart_strategy {
-                RestartStrategy::OneForOne => RestartStrategy::OneForOne,
-                RestartStrategy::OneForAll => RestartStrategy::OneForAll,
-                RestartStrategy::RestForOne => RestartStrategy::RestForOne,
-            },
+            restart_strategy: self.restart_strategy.clone(),
+            actor_restart_strategy: self.actor_restart_strategy.clone(),
+            shutdown_timeout: self.shutdown_timeout,
         }
     }
 }
@@ -316,6 +1122,24 @@ impl Clone for RestartStrategy {
     }
 }
 
+// Clone implementation for ActorRestartStrategy
+impl Clone for ActorRestartStrategy {
+    fn clone(&self) -> Self {
+        match self {
+            ActorRestartStrategy::Immediate => ActorRestartStrategy::Immediate,
+            ActorRestartStrategy::LinearBackOff { base } => {
+                ActorRestartStrategy::LinearBackOff { base: *base }
+            }
+            ActorRestartStrategy::ExponentialBackOff { base, multiplier } => {
+                ActorRestartStrategy::ExponentialBackOff {
+                    base: *base,
+                    multiplier: *multiplier,
+                }
+            }
+        }
+    }
+}
+
 // Clone implementation for ProcessState
 impl Clone for ProcessState {
     fn clone(&self) -> Self {
@@ -323,7 +1147,234 @@ impl Clone for ProcessState {
             ProcessState::Running => ProcessState::Running,
             ProcessState::Failed => ProcessState::Failed,
             ProcessState::Restarting => ProcessState::Restarting,
+            ProcessState::Stopping => ProcessState::Stopping,
             ProcessState::Stopped => ProcessState::Stopped,
+            ProcessState::Completed => ProcessState::Completed,
+        }
+    }
+}
+
+// Clone implementation for RestartPolicy
+impl Clone for RestartPolicy {
+    fn clone(&self) -> Self {
+        match self {
+            RestartPolicy::Always => RestartPolicy::Always,
+            RestartPolicy::OnFailureOnly => RestartPolicy::OnFailureOnly,
+            RestartPolicy::Temporary => RestartPolicy::Temporary,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+
+    #[test]
+    fn delay_for_immediate_is_always_zero() {
+        let strategy = ActorRestartStrategy::Immediate;
+        assert_eq!(strategy.delay_for(0), Duration::from_secs(0));
+        assert_eq!(strategy.delay_for(5), Duration::from_secs(0));
+    }
+
+    #[test]
+    fn delay_for_linear_waits_base_on_first_restart() {
+        let strategy = ActorRestartStrategy::LinearBackOff {
+            base: Duration::from_millis(100),
+        };
+        assert_eq!(strategy.delay_for(0), Duration::from_millis(100));
+        assert_eq!(strategy.delay_for(1), Duration::from_millis(200));
+        assert_eq!(strategy.delay_for(3), Duration::from_millis(400));
+    }
+
+    #[test]
+    fn delay_for_linear_saturates_instead_of_overflowing() {
+        let strategy = ActorRestartStrategy::LinearBackOff {
+            base: Duration::from_secs(u64::MAX),
+        };
+        assert_eq!(strategy.delay_for(u32::MAX), Duration::MAX);
+    }
+
+    #[test]
+    fn delay_for_exponential_waits_base_on_first_restart() {
+        let strategy = ActorRestartStrategy::ExponentialBackOff {
+            base: Duration::from_millis(100),
+            multiplier: 2,
+        };
+        assert_eq!(strategy.delay_for(0), Duration::from_millis(100));
+        assert_eq!(strategy.delay_for(1), Duration::from_millis(200));
+        assert_eq!(strategy.delay_for(2), Duration::from_millis(400));
+    }
+
+    #[test]
+    fn delay_for_exponential_saturates_instead_of_panicking() {
+        let strategy = ActorRestartStrategy::ExponentialBackOff {
+            base: Duration::from_secs(u64::MAX),
+            multiplier: 10,
+        };
+        assert_eq!(strategy.delay_for(u32::MAX), Duration::MAX);
+    }
+
+    #[test]
+    fn panic_is_restarted_under_always() {
+        let mut supervisor = Supervisor::new(SupervisorConfig::default());
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let attempts_clone = Arc::clone(&attempts);
+        supervisor.add_process_with_policy(
+            "panics",
+            move |_stop_flag| {
+                let attempts = Arc::clone(&attempts_clone);
+                thread::spawn(move || {
+                    attempts.fetch_add(1, Ordering::SeqCst);
+                    panic!("boom");
+                })
+            },
+            RestartPolicy::Always,
+        );
+        supervisor.start_monitoring();
+
+        thread::sleep(Duration::from_millis(600));
+        assert!(attempts.load(Ordering::SeqCst) >= 2);
+    }
+
+    #[test]
+    fn clean_exit_is_completed_and_not_restarted_under_on_failure_only() {
+        let mut supervisor = Supervisor::new(SupervisorConfig::default());
+        let runs = Arc::new(AtomicUsize::new(0));
+        let runs_clone = Arc::clone(&runs);
+        supervisor.add_process_with_policy(
+            "finishes_cleanly",
+            move |_stop_flag| {
+                let runs = Arc::clone(&runs_clone);
+                thread::spawn(move || {
+                    runs.fetch_add(1, Ordering::SeqCst);
+                })
+            },
+            RestartPolicy::OnFailureOnly,
+        );
+        supervisor.start_monitoring();
+
+        thread::sleep(Duration::from_millis(400));
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+        assert!(matches!(
+            supervisor.get_process_state("finishes_cleanly"),
+            Some(ProcessState::Completed)
+        ));
+    }
+
+    #[test]
+    fn panic_is_restarted_under_on_failure_only() {
+        let mut supervisor = Supervisor::new(SupervisorConfig::default());
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let attempts_clone = Arc::clone(&attempts);
+        supervisor.add_process_with_policy(
+            "panics",
+            move |_stop_flag| {
+                let attempts = Arc::clone(&attempts_clone);
+                thread::spawn(move || {
+                    attempts.fetch_add(1, Ordering::SeqCst);
+                    panic!("boom");
+                })
+            },
+            RestartPolicy::OnFailureOnly,
+        );
+        supervisor.start_monitoring();
+
+        thread::sleep(Duration::from_millis(600));
+        assert!(attempts.load(Ordering::SeqCst) >= 2);
+    }
+
+    #[test]
+    fn temporary_process_never_restarts_after_clean_exit() {
+        let mut supervisor = Supervisor::new(SupervisorConfig::default());
+        let runs = Arc::new(AtomicUsize::new(0));
+        let runs_clone = Arc::clone(&runs);
+        supervisor.add_process_with_policy(
+            "one_shot",
+            move |_stop_flag| {
+                let runs = Arc::clone(&runs_clone);
+                thread::spawn(move || {
+                    runs.fetch_add(1, Ordering::SeqCst);
+                })
+            },
+            RestartPolicy::Temporary,
+        );
+        supervisor.start_monitoring();
+
+        thread::sleep(Duration::from_millis(400));
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+        assert!(matches!(
+            supervisor.get_process_state("one_shot"),
+            Some(ProcessState::Stopped)
+        ));
+    }
+
+    #[test]
+    fn temporary_process_never_restarts_after_panic() {
+        let mut supervisor = Supervisor::new(SupervisorConfig::default());
+        let attempts = Arc::new(AtomicUsize::new(0));
+        let attempts_clone = Arc::clone(&attempts);
+        supervisor.add_process_with_policy(
+            "one_shot",
+            move |_stop_flag| {
+                let attempts = Arc::clone(&attempts_clone);
+                thread::spawn(move || {
+                    attempts.fetch_add(1, Ordering::SeqCst);
+                    panic!("boom");
+                })
+            },
+            RestartPolicy::Temporary,
+        );
+        supervisor.start_monitoring();
+
+        thread::sleep(Duration::from_millis(400));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn manual_stop_does_not_flag_parent_as_exhausted() {
+        let mut parent = Supervisor::new(SupervisorConfig::default());
+        let mut child = Supervisor::new(SupervisorConfig::default());
+        child.add_process("worker", |stop_flag| {
+            thread::spawn(move || {
+                while !stop_flag.load(Ordering::SeqCst) {
+                    thread::sleep(Duration::from_millis(5));
+                }
+            })
+        });
+        parent.add_supervisor("child_tree", child);
+        parent.start_monitoring();
+
+        thread::sleep(Duration::from_millis(150));
+        if let Some(Supervisee::Supervisor(child_ref)) =
+            parent.processes.lock().unwrap().get("child_tree")
+        {
+            child_ref.stop_process("worker");
+        }
+
+        thread::sleep(Duration::from_millis(300));
+        assert!(!parent.exhausted.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn exhausted_restart_budget_flags_parent_as_exhausted() {
+        let mut parent = Supervisor::new(SupervisorConfig {
+            max_restarts: 1,
+            max_time: Duration::from_secs(5),
+            ..SupervisorConfig::default()
+        });
+        let mut child = Supervisor::new(SupervisorConfig {
+            max_restarts: 1,
+            max_time: Duration::from_secs(5),
+            ..SupervisorConfig::default()
+        });
+        child.add_process("flaky", |_stop_flag| {
+            thread::spawn(|| panic!("always fails"))
+        });
+        parent.add_supervisor("child_tree", child);
+        parent.start_monitoring();
+
+        thread::sleep(Duration::from_millis(3000));
+        assert!(parent.exhausted.load(Ordering::SeqCst));
+    }
+}